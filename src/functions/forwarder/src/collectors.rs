@@ -10,18 +10,174 @@
 use anyhow::{Context, Result};
 use aws_sdk_secretsmanager::types::Filter;
 use aws_sdk_secretsmanager::Client as SecretsManagerClient;
+use base64::Engine;
+use dashmap::DashMap;
+use ipnet::IpNet;
+use opentelemetry::metrics::{Counter, Histogram};
+use opentelemetry::{global, KeyValue};
+use rand::Rng;
 use regex::Regex;
+use ring::digest::{digest, SHA256};
+use ring::rand::SystemRandom;
+use ring::signature::{RsaKeyPair, RSA_PKCS1_SHA256};
 use serde::{Deserialize, Deserializer};
 use std::env;
+use std::net::{IpAddr, ToSocketAddrs};
 use std::sync::Arc;
 use std::sync::OnceLock;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 use tracing::instrument;
 use url::Url;
 
 /// Global storage for cached collectors configuration
 static COLLECTORS: OnceLock<Arc<CollectorsCache>> = OnceLock::new();
 
+/// Global per-authority circuit breakers, mirroring [`COLLECTORS`].
+static BREAKERS: OnceLock<Breakers> = OnceLock::new();
+
+/// Returns the process-wide [`Breakers`], initializing it from the environment
+/// on first use. The forwarding code reports delivery outcomes through this
+/// handle via [`Breakers::record_success`]/[`Breakers::record_failure`].
+pub fn breakers() -> &'static Breakers {
+    BREAKERS.get_or_init(Breakers::from_env)
+}
+
+/// The observable state of a single circuit breaker.
+#[derive(Debug, Clone, PartialEq)]
+enum BreakerState {
+    /// Requests flow normally.
+    Closed,
+    /// The endpoint is considered dead until `until` elapses.
+    Open { until: Instant },
+    /// A single recovery probe is outstanding; further requests are refused
+    /// until that probe's outcome is reported.
+    HalfOpen,
+}
+
+/// Tracks consecutive-failure state for one collector authority.
+#[derive(Debug)]
+struct Breaker {
+    failures: u32,
+    state: BreakerState,
+}
+
+impl Breaker {
+    fn new() -> Self {
+        Self {
+            failures: 0,
+            state: BreakerState::Closed,
+        }
+    }
+}
+
+/// Container of circuit breakers keyed by URL authority (`host:port`).
+///
+/// A breaker trips to [`BreakerState::Open`] after `threshold` consecutive
+/// failures and stays open for `cooldown`, after which a single half-open
+/// probe decides whether to close again.
+#[derive(Debug)]
+pub struct Breakers {
+    inner: DashMap<String, Breaker>,
+    threshold: u32,
+    cooldown: Duration,
+}
+
+impl Breakers {
+    /// Builds breakers using thresholds from the environment, defaulting to
+    /// opening after 5 consecutive failures and a 30 second cooldown.
+    fn from_env() -> Self {
+        let threshold = env::var("BREAKER_FAILURE_THRESHOLD")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(5);
+
+        let cooldown_seconds = env::var("BREAKER_COOLDOWN_SECONDS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(30);
+
+        tracing::debug!(
+            "Using circuit breaker threshold {} and cooldown {}s",
+            threshold,
+            cooldown_seconds
+        );
+
+        Self {
+            inner: DashMap::new(),
+            threshold,
+            cooldown: Duration::from_secs(cooldown_seconds),
+        }
+    }
+
+    /// Extracts the `host:port` authority used to key breakers for an endpoint.
+    fn authority(endpoint: &str) -> Option<String> {
+        Url::parse(endpoint)
+            .ok()
+            .map(|url| url.authority().to_string())
+    }
+
+    /// Returns whether a request to `endpoint` should be attempted.
+    ///
+    /// In [`BreakerState::Closed`] this is always true; in
+    /// [`BreakerState::Open`] it returns true exactly once the cooldown elapsed,
+    /// transitioning to [`BreakerState::HalfOpen`] to mark that probe as
+    /// outstanding; while [`BreakerState::HalfOpen`] it returns false so only a
+    /// single probe reaches a still-fragile endpoint until its outcome is
+    /// reported via [`Breakers::record_success`]/[`Breakers::record_failure`].
+    pub fn should_try(&self, endpoint: &str) -> bool {
+        let Some(authority) = Self::authority(endpoint) else {
+            // Unparseable endpoints are handled downstream; don't block them here.
+            return true;
+        };
+
+        let mut breaker = self.inner.entry(authority).or_insert_with(Breaker::new);
+        match breaker.state {
+            BreakerState::Closed => true,
+            // A probe is already in flight; admit no further requests.
+            BreakerState::HalfOpen => false,
+            BreakerState::Open { until } => {
+                if Instant::now() >= until {
+                    // Claim the single recovery probe for this caller.
+                    breaker.state = BreakerState::HalfOpen;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Records a successful forward, resetting the breaker to closed.
+    pub fn record_success(&self, endpoint: &str) {
+        let Some(authority) = Self::authority(endpoint) else {
+            return;
+        };
+
+        let mut breaker = self.inner.entry(authority).or_insert_with(Breaker::new);
+        breaker.failures = 0;
+        breaker.state = BreakerState::Closed;
+    }
+
+    /// Records a failed forward, tripping the breaker open at the threshold and
+    /// re-opening immediately on any half-open failure.
+    pub fn record_failure(&self, endpoint: &str) {
+        let Some(authority) = Self::authority(endpoint) else {
+            return;
+        };
+
+        let mut breaker = self.inner.entry(authority).or_insert_with(Breaker::new);
+        breaker.failures += 1;
+
+        let trip = matches!(breaker.state, BreakerState::HalfOpen)
+            || breaker.failures >= self.threshold;
+        if trip {
+            breaker.state = BreakerState::Open {
+                until: Instant::now() + self.cooldown,
+            };
+        }
+    }
+}
+
 /// Represents a single collector configuration.
 /// Each collector has a name, endpoint, and optional authentication details.
 #[derive(Debug, Clone, Deserialize)]
@@ -32,12 +188,304 @@ pub struct Collector {
     pub endpoint: String,
     /// Optional authentication string. Special values:
     /// - "sigv4" or "iam": Use AWS SigV4 signing
+    /// - "httpsig:<secret-name>": Sign requests with the RSA private key stored
+    ///   in the named Secrets Manager entry (see [`HttpSigner`])
     /// - "header_name=value": Add a custom header
     /// - null or empty: No authentication
     pub auth: Option<String>,
     /// Optional regex pattern to exclude certain log groups
     #[serde(default, deserialize_with = "deserialize_regex")]
     pub exclude: Option<Regex>,
+    /// Optional regex pattern restricting this collector to matching log groups.
+    /// When present, a source must match it for the collector to receive signals.
+    #[serde(default, deserialize_with = "deserialize_regex")]
+    pub include: Option<Regex>,
+    /// Optional routing priority. When at least one matching collector sets a
+    /// priority, only the highest-priority matching collectors that the
+    /// circuit breaker admits receive the signal; a tier with none admitted
+    /// falls through to the next lower priority. Collectors that leave
+    /// `priority` unset are treated as lower than any explicit priority and are
+    /// therefore dropped in that case. When no matching collector sets a
+    /// priority, every match receives the signal.
+    #[serde(default)]
+    pub priority: Option<i64>,
+    /// Per-collector retry policy, defaulted when absent from the secret JSON.
+    #[serde(default)]
+    pub retry: RetryPolicy,
+    /// Decoded HTTP-signature key, populated at load time when `auth` uses the
+    /// `httpsig:` scheme. Never (de)serialized from the secret itself.
+    #[serde(skip)]
+    pub(crate) signer: Option<Arc<HttpSigner>>,
+}
+
+/// Holds a parsed RSA key pair used to sign outgoing requests for collectors
+/// configured with `auth: "httpsig:<secret-name>"`.
+///
+/// Implements the Cavage/RFC-9421-style scheme covering the
+/// `(request-target)`, `host`, `date`, and `digest` components.
+pub(crate) struct HttpSigner {
+    /// `keyId` advertised in the `Signature` header, the secret name.
+    key_id: String,
+    key_pair: RsaKeyPair,
+}
+
+impl std::fmt::Debug for HttpSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HttpSigner")
+            .field("key_id", &self.key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HttpSigner {
+    /// Parses a PEM-encoded RSA private key (PKCS#8 or PKCS#1) into a signer.
+    fn from_pem(key_id: &str, pem: &str) -> Result<Self> {
+        let der = pem::parse(pem)
+            .context("collector httpsig key is not valid PEM")?
+            .into_contents();
+
+        let key_pair = RsaKeyPair::from_pkcs8(&der)
+            .or_else(|_| RsaKeyPair::from_der(&der))
+            .map_err(|e| anyhow::anyhow!("invalid RSA private key: {e}"))?;
+
+        Ok(Self {
+            key_id: key_id.to_string(),
+            key_pair,
+        })
+    }
+
+    /// Builds the `Digest`, `Date`, and `Signature` headers for a request to
+    /// `url` with the given method and body.
+    ///
+    /// The signing string concatenates the covered components newline-separated
+    /// as `name: value`, and the signature is RSA PKCS#1 v1.5 over SHA-256.
+    pub(crate) fn sign(
+        &self,
+        method: &str,
+        url: &Url,
+        body: &[u8],
+    ) -> Result<Vec<(String, String)>> {
+        let host = host_header_value(url);
+        let date = httpdate::fmt_http_date(SystemTime::now());
+
+        let digest_value = format!(
+            "SHA-256={}",
+            base64::engine::general_purpose::STANDARD.encode(digest(&SHA256, body).as_ref())
+        );
+
+        let request_target = format!("{} {}", method.to_lowercase(), url.path());
+        let signing_string = format!(
+            "(request-target): {request_target}\nhost: {host}\ndate: {date}\ndigest: {digest_value}"
+        );
+
+        let mut signature = vec![0u8; self.key_pair.public().modulus_len()];
+        self.key_pair
+            .sign(
+                &RSA_PKCS1_SHA256,
+                &SystemRandom::new(),
+                signing_string.as_bytes(),
+                &mut signature,
+            )
+            .map_err(|e| anyhow::anyhow!("failed to sign request: {e}"))?;
+        let signature_b64 = base64::engine::general_purpose::STANDARD.encode(&signature);
+
+        let signature_header = format!(
+            "keyId=\"{}\",algorithm=\"rsa-sha256\",headers=\"(request-target) host date digest\",signature=\"{signature_b64}\"",
+            self.key_id
+        );
+
+        Ok(vec![
+            ("Digest".to_string(), digest_value),
+            ("Date".to_string(), date),
+            ("Signature".to_string(), signature_header),
+        ])
+    }
+}
+
+/// Derives the `host` signing component the way reqwest derives the
+/// transmitted `Host` header: the hostname alone, plus an explicit `:port`
+/// suffix only when `url` carries a non-default port. Using `url.authority()`
+/// directly would instead include any userinfo and diverge from what's
+/// actually sent, producing a signature the gateway can't verify.
+fn host_header_value(url: &Url) -> String {
+    let host = url.host_str().unwrap_or_default();
+    match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    }
+}
+
+/// Per-collector retry configuration deserialized from the secret JSON.
+///
+/// Absent fields fall back to the defaults returned by [`RetryPolicy::default`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the initial attempt.
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// Base delay in milliseconds doubled on each attempt.
+    #[serde(default = "default_base_delay_ms")]
+    pub base_delay_ms: u64,
+    /// Upper bound in milliseconds for any single backoff delay.
+    #[serde(default = "default_max_delay_ms")]
+    pub max_delay_ms: u64,
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+fn default_base_delay_ms() -> u64 {
+    100
+}
+
+fn default_max_delay_ms() -> u64 {
+    5_000
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: default_max_retries(),
+            base_delay_ms: default_base_delay_ms(),
+            max_delay_ms: default_max_delay_ms(),
+        }
+    }
+}
+
+/// Classification of a single forward attempt, driving [`forward_with_retry`].
+pub enum AttemptOutcome {
+    /// The collector accepted the telemetry.
+    Success,
+    /// A transient failure (connection error or 429/5xx); retry if budget
+    /// remains, honoring any server-provided `Retry-After`.
+    Retriable { retry_after: Option<Duration> },
+    /// A non-retriable failure; give up immediately.
+    Fatal,
+}
+
+impl RetryPolicy {
+    /// Computes the full-jitter backoff delay for a 0-indexed `attempt`:
+    /// a random duration in `[0, min(max_delay_ms, base_delay_ms * 2^attempt)]`.
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let exp = self.base_delay_ms.saturating_mul(1u64 << attempt.min(63));
+        let cap = self.max_delay_ms.min(exp);
+        let jitter = rand::thread_rng().gen_range(0..=cap);
+        Duration::from_millis(jitter)
+    }
+}
+
+/// Drives a single collector's forward attempts through its [`RetryPolicy`],
+/// applying full-jitter exponential backoff and reporting the overall outcome
+/// to the circuit breaker exactly once.
+///
+/// `attempt` is invoked for the initial try and each retry; retries are per
+/// collector and independent, so a slow collector never blocks the others.
+/// Returns `true` when the collector ultimately accepted the telemetry.
+pub async fn forward_with_retry<F, Fut>(
+    collector_name: &str,
+    endpoint: &str,
+    policy: &RetryPolicy,
+    mut attempt: F,
+) -> bool
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = AttemptOutcome>,
+{
+    for n in 0..=policy.max_retries {
+        match attempt().await {
+            AttemptOutcome::Success => {
+                breakers().record_success(endpoint);
+                return true;
+            }
+            AttemptOutcome::Fatal => break,
+            AttemptOutcome::Retriable { retry_after } => {
+                if n == policy.max_retries {
+                    break;
+                }
+                let delay = retry_after.unwrap_or_else(|| policy.backoff_delay(n));
+                tracing::warn!(
+                    "Collector '{}' forward failed, retrying (attempt {}/{}) in {}ms",
+                    collector_name,
+                    n + 1,
+                    policy.max_retries,
+                    delay.as_millis()
+                );
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+
+    // Every attempt exhausted: a single breaker failure for this collector.
+    breakers().record_failure(endpoint);
+    false
+}
+
+/// Aggregate forwarding metrics, recorded per attempt and tagged with the
+/// collector name and signal type.
+struct ForwarderMetrics {
+    requests: Counter<u64>,
+    errors: Counter<u64>,
+    duration: Histogram<f64>,
+}
+
+/// Lazily-initialized forwarding instruments for the `otlp-forwarder` meter.
+static METRICS: OnceLock<ForwarderMetrics> = OnceLock::new();
+
+fn metrics() -> &'static ForwarderMetrics {
+    METRICS.get_or_init(|| {
+        let meter = global::meter("otlp-forwarder");
+        ForwarderMetrics {
+            requests: meter.u64_counter("forwarder.requests").build(),
+            errors: meter.u64_counter("forwarder.errors").build(),
+            duration: meter
+                .f64_histogram("forwarder.request_duration")
+                .with_unit("s")
+                .build(),
+        }
+    })
+}
+
+/// Derives the OTLP signal type (`traces`/`logs`/`metrics`) from a signal path
+/// such as `/v1/traces`, matching the path handled in
+/// [`Collector::construct_signal_endpoint`].
+pub fn signal_type(signal_path: &str) -> &'static str {
+    if signal_path.contains("/traces") {
+        "traces"
+    } else if signal_path.contains("/logs") {
+        "logs"
+    } else if signal_path.contains("/metrics") {
+        "metrics"
+    } else {
+        "unknown"
+    }
+}
+
+/// Times a single forward attempt and records the request/error counters and
+/// the duration histogram regardless of success, tagged with `collector.name`
+/// and the signal type.
+pub async fn record_forward<F, Fut, T, E>(collector_name: &str, signal: &str, send: F) -> Result<T, E>
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = Result<T, E>>,
+{
+    let attrs = [
+        KeyValue::new("collector.name", collector_name.to_string()),
+        KeyValue::new("signal", signal.to_string()),
+    ];
+
+    let m = metrics();
+    m.requests.add(1, &attrs);
+
+    let start = Instant::now();
+    let result = send().await;
+    m.duration.record(start.elapsed().as_secs_f64(), &attrs);
+
+    if result.is_err() {
+        m.errors.add(1, &attrs);
+    }
+
+    result
 }
 
 fn deserialize_regex<'de, D>(deserializer: D) -> Result<Option<Regex>, D::Error>
@@ -154,22 +602,87 @@ impl Collectors {
     pub fn get_signal_endpoints(original_endpoint: &str, source: &str) -> Result<Vec<Collector>> {
         let cache = COLLECTORS.get().expect("Collectors cache not initialized");
 
-        cache
+        // Collectors whose include/exclude rules match this source.
+        let matching: Vec<&Collector> = cache
             .inner
             .items
             .iter()
-            .filter(|collector| !collector.should_exclude(source))
-            .map(|collector| {
-                let endpoint = collector.construct_signal_endpoint(original_endpoint)?;
-                Ok(Collector {
-                    name: collector.name.clone(),
-                    endpoint,
-                    auth: collector.auth.clone(),
-                    exclude: collector.exclude.clone(),
-                })
+            .filter(|collector| collector.matches(source))
+            .collect();
+
+        Ok(select_endpoints(&matching, original_endpoint))
+    }
+}
+
+/// Selects which of `matching` collectors should receive a signal and
+/// constructs their full endpoint URLs.
+///
+/// When any matching collector declares a priority, distinct priorities are
+/// tried from highest to lowest; the first tier with at least one collector
+/// whose circuit breaker admits the request wins, so a fully circuit-broken
+/// top tier fails over to the next tier instead of blackholing the signal.
+/// Collectors that leave `priority` unset are treated as lower than any
+/// explicit priority and are excluded from every tier whenever at least one
+/// match sets one. When no matching collector declares a priority, every
+/// match receives the signal (legacy behavior). If every tier is exhausted
+/// with nothing admitted, the signal is intentionally dropped.
+///
+/// Extracted from [`Collectors::get_signal_endpoints`] so the selection logic
+/// can be exercised directly in tests without touching the global collectors
+/// cache.
+fn select_endpoints(matching: &[&Collector], original_endpoint: &str) -> Vec<Collector> {
+    let mut priorities: Vec<i64> = matching
+        .iter()
+        .filter_map(|collector| collector.priority)
+        .collect();
+    priorities.sort_unstable_by(|a, b| b.cmp(a));
+    priorities.dedup();
+    let tiers: Vec<Option<i64>> = if priorities.is_empty() {
+        vec![None]
+    } else {
+        priorities.into_iter().map(Some).collect()
+    };
+
+    for tier in tiers {
+        let endpoints: Vec<Collector> = matching
+            .iter()
+            .filter(|collector| collector.priority == tier)
+            .filter_map(|collector| {
+                // Construct the endpoint before consulting the breaker: a
+                // malformed endpoint is skipped without ever claiming a
+                // half-open recovery probe that nothing would then report an
+                // outcome for.
+                match collector.construct_signal_endpoint(original_endpoint) {
+                    Ok(endpoint) => Some((*collector, endpoint)),
+                    Err(e) => {
+                        tracing::warn!(
+                            "Collector '{}': failed to construct signal endpoint: {}. Skipping.",
+                            collector.name,
+                            e
+                        );
+                        None
+                    }
+                }
+            })
+            .filter(|(collector, _)| breakers().should_try(&collector.endpoint))
+            .map(|(collector, endpoint)| Collector {
+                name: collector.name.clone(),
+                endpoint,
+                auth: collector.auth.clone(),
+                exclude: collector.exclude.clone(),
+                include: collector.include.clone(),
+                priority: collector.priority,
+                retry: collector.retry.clone(),
+                signer: collector.signer.clone(),
             })
-            .collect()
+            .collect();
+
+        if !endpoints.is_empty() {
+            return endpoints;
+        }
     }
+
+    Vec::new()
 }
 
 impl Collector {
@@ -205,6 +718,131 @@ impl Collector {
         }
         false
     }
+
+    /// Decides whether this collector should receive signals from `source`.
+    ///
+    /// A collector matches only if its `include` pattern is absent or matches,
+    /// and its `exclude` pattern is absent or does not match.
+    pub(crate) fn matches(&self, source: &str) -> bool {
+        let included = self
+            .include
+            .as_ref()
+            .map(|pattern| pattern.is_match(source))
+            .unwrap_or(true);
+
+        included && !self.should_exclude(source)
+    }
+
+    /// Sends a single OTLP request body to this collector's endpoint.
+    async fn send_once(
+        &self,
+        client: &reqwest::Client,
+        body: &[u8],
+    ) -> std::result::Result<reqwest::Response, ForwardError> {
+        let url = Url::parse(&self.endpoint).map_err(|e| ForwardError::Build(e.to_string()))?;
+
+        let mut req = client.post(url.clone()).body(body.to_vec());
+
+        // Apply authentication. An httpsig key takes precedence and signs the
+        // request with a Cavage-style `Signature` header (see `HttpSigner`);
+        // otherwise a "header_name=value" auth adds a
+        // static header. sigv4/iam are handled upstream by the signing client.
+        if let Some(signer) = &self.signer {
+            let headers = signer
+                .sign("POST", &url, body)
+                .map_err(|e| ForwardError::Build(e.to_string()))?;
+            for (name, value) in headers {
+                req = req.header(name, value);
+            }
+        } else if let Some((name, value)) = self
+            .auth
+            .as_deref()
+            .filter(|a| !matches!(*a, "sigv4" | "iam"))
+            .and_then(|a| a.split_once('='))
+        {
+            req = req.header(name, value);
+        }
+
+        let resp = req.send().await.map_err(ForwardError::Transport)?;
+        let status = resp.status();
+        if status.is_success() {
+            Ok(resp)
+        } else {
+            Err(ForwardError::Status {
+                code: status.as_u16(),
+                retry_after: parse_retry_after(resp.headers()),
+            })
+        }
+    }
+
+    /// Forwards a signal body to this collector with its retry policy applied.
+    ///
+    /// Transient failures (connection errors and 429/5xx responses) are retried
+    /// with full-jitter exponential backoff; the overall outcome is reported to
+    /// the circuit breaker so repeatedly-failing endpoints get skipped.
+    ///
+    /// Returns `true` when the collector accepted the telemetry.
+    pub async fn forward(&self, client: &reqwest::Client, body: &[u8]) -> bool {
+        let signal_path = Url::parse(&self.endpoint)
+            .map(|u| u.path().to_string())
+            .unwrap_or_default();
+        let signal = signal_type(&signal_path);
+
+        forward_with_retry(&self.name, &self.endpoint, &self.retry, || async {
+            // Record per-attempt request/error counters and duration.
+            match record_forward(&self.name, signal, || self.send_once(client, body)).await {
+                Ok(_) => AttemptOutcome::Success,
+                Err(ForwardError::Status { code, retry_after })
+                    if code == 429 || (500..600).contains(&code) =>
+                {
+                    AttemptOutcome::Retriable { retry_after }
+                }
+                Err(ForwardError::Transport(ref e))
+                    if e.is_connect() || e.is_timeout() || e.is_request() =>
+                {
+                    AttemptOutcome::Retriable { retry_after: None }
+                }
+                Err(e) => {
+                    tracing::warn!("Forward to collector '{}' failed: {}", self.name, e);
+                    AttemptOutcome::Fatal
+                }
+            }
+        })
+        .await
+    }
+}
+
+/// Failure modes for a single forward attempt.
+#[derive(Debug)]
+enum ForwardError {
+    /// The request could not be constructed (e.g. an unparseable endpoint).
+    Build(String),
+    /// The HTTP request failed at the transport layer.
+    Transport(reqwest::Error),
+    /// The collector returned a non-success status.
+    Status {
+        code: u16,
+        retry_after: Option<Duration>,
+    },
+}
+
+impl std::fmt::Display for ForwardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ForwardError::Build(msg) => write!(f, "request build error: {msg}"),
+            ForwardError::Transport(e) => write!(f, "transport error: {e}"),
+            ForwardError::Status { code, .. } => write!(f, "collector returned status {code}"),
+        }
+    }
+}
+
+/// Parses a `Retry-After` header expressed in delta-seconds into a [`Duration`].
+fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
 }
 
 /// Fetches collectors configuration from AWS Secrets Manager
@@ -251,7 +889,42 @@ async fn fetch_collectors(client: &SecretsManagerClient) -> Result<Vec<Collector
     for secret in response.secret_values() {
         if let Some(secret_string) = secret.secret_string() {
             match serde_json::from_str::<Collector>(secret_string) {
-                Ok(collector) => {
+                Ok(mut collector) => {
+                    // Reject endpoints that would let a bad secret point the
+                    // forwarder at internal/private hosts (SSRF), dropping just
+                    // this collector like an invalid regex or parse failure.
+                    if let Err(e) = validate_endpoint(&collector.endpoint).await {
+                        tracing::warn!(
+                            "Skipping collector '{}': endpoint failed validation: {}",
+                            collector.name,
+                            e
+                        );
+                        continue;
+                    }
+
+                    // Load and cache the HTTP-signature key once per collector so
+                    // signing never re-parses PEM on the request path.
+                    if let Some(key_name) = collector
+                        .auth
+                        .as_deref()
+                        .and_then(|a| a.strip_prefix("httpsig:"))
+                    {
+                        match load_httpsig_signer(client, key_name).await {
+                            Ok(signer) => collector.signer = Some(Arc::new(signer)),
+                            Err(e) => {
+                                // Mirror the invalid-regex handling: warn and
+                                // treat the key as absent rather than dropping
+                                // the whole collector.
+                                tracing::warn!(
+                                    "Collector '{}': failed to load httpsig key '{}': {}. Forwarding without signing.",
+                                    collector.name,
+                                    key_name,
+                                    e
+                                );
+                            }
+                        }
+                    }
+
                     tracing::debug!(
                         "Successfully loaded collector '{}' from secret {}",
                         collector.name,
@@ -278,6 +951,190 @@ async fn fetch_collectors(client: &SecretsManagerClient) -> Result<Vec<Collector
     Ok(collectors)
 }
 
+/// Returns whether an IP address falls in a range we refuse to forward to by
+/// default: loopback, link-local (including the cloud metadata service at
+/// `169.254.169.254`), unspecified, broadcast, RFC-1918 / unique-local private
+/// space, and CGNAT (`100.64.0.0/10`).
+///
+/// IPv4-mapped IPv6 literals (e.g. `::ffff:169.254.169.254`) are unmapped to
+/// their IPv4 form first, and IPv4-compatible ones are checked as IPv4 after
+/// the V6 loopback/unspecified checks, so neither can slip past the V4 checks
+/// under a V6 disguise.
+fn is_blocked_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_blocked_ipv4(v4),
+        IpAddr::V6(v6) => {
+            // Only the `::ffff:0:0/96` mapped form is unmapped eagerly: it
+            // represents the same address as the embedded IPv4 literal. The
+            // deprecated `::/96` IPv4-compatible form (`to_ipv4`) also embeds
+            // an IPv4 address, but naively unmapping it first would let it
+            // bypass the V6 loopback/unspecified checks below — notably
+            // `::1`, which `to_ipv4` maps to the non-special `0.0.0.1`. So
+            // it's folded into the OR chain instead, after those checks.
+            if let Some(v4) = v6.to_ipv4_mapped() {
+                return is_blocked_ipv4(v4);
+            }
+
+            v6.is_loopback()
+                || v6.is_unspecified()
+                || v6.to_ipv4().is_some_and(is_blocked_ipv4)
+                // fc00::/7 unique-local
+                || (v6.segments()[0] & 0xfe00) == 0xfc00
+                // fe80::/10 link-local
+                || (v6.segments()[0] & 0xffc0) == 0xfe80
+        }
+    }
+}
+
+/// Returns whether an IPv4 address falls in a blocked range; shared by the
+/// direct `IpAddr::V4` case and unmapped IPv6-mapped/compatible literals.
+fn is_blocked_ipv4(v4: std::net::Ipv4Addr) -> bool {
+    v4.is_loopback()
+        || v4.is_private()
+        || v4.is_link_local()
+        || v4.is_unspecified()
+        || v4.is_broadcast()
+        // 100.64.0.0/10 carrier-grade NAT
+        || (v4.octets()[0] == 100 && (v4.octets()[1] & 0xc0) == 0x40)
+}
+
+/// Parsed form of the `COLLECTOR_HOST_ALLOWLIST` env var: each comma-separated
+/// entry is either a CIDR range or, failing that, a host-name suffix.
+struct Allowlist {
+    cidrs: Vec<IpNet>,
+    suffixes: Vec<String>,
+}
+
+impl Allowlist {
+    fn from_env() -> Self {
+        let mut cidrs = Vec::new();
+        let mut suffixes = Vec::new();
+
+        if let Ok(raw) = env::var("COLLECTOR_HOST_ALLOWLIST") {
+            for entry in raw.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+                match entry.parse::<IpNet>() {
+                    Ok(net) => cidrs.push(net),
+                    Err(_) => suffixes.push(entry.to_ascii_lowercase()),
+                }
+            }
+        }
+
+        Self { cidrs, suffixes }
+    }
+
+    /// Whether any host suffixes are configured.
+    fn has_suffixes(&self) -> bool {
+        !self.suffixes.is_empty()
+    }
+
+    /// Whether a DNS host name is explicitly permitted by suffix, anchored to a
+    /// label boundary so `example.com` matches `api.example.com` and
+    /// `example.com` but not `evilexample.com`.
+    fn allows_host(&self, host: &str) -> bool {
+        let host = host.to_ascii_lowercase();
+        self.suffixes.iter().any(|s| {
+            let s = s.trim_start_matches('.');
+            host == s || host.ends_with(&format!(".{s}"))
+        })
+    }
+
+    /// Whether a resolved IP is explicitly permitted by CIDR.
+    fn allows_ip(&self, ip: IpAddr) -> bool {
+        self.cidrs.iter().any(|net| net.contains(&ip))
+    }
+}
+
+/// Validates a collector endpoint against the SSRF policy before it is used as
+/// a forwarding target.
+///
+/// Requires `https` unless `COLLECTOR_ALLOW_INSECURE` is `1` or `true`, and rejects hosts
+/// (or any of their resolved addresses) that fall in a blocked range unless the
+/// `COLLECTOR_HOST_ALLOWLIST` explicitly permits them.
+async fn validate_endpoint(endpoint: &str) -> Result<()> {
+    let url = Url::parse(endpoint).with_context(|| format!("invalid endpoint URL: {endpoint}"))?;
+
+    let allow_insecure = env::var("COLLECTOR_ALLOW_INSECURE")
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false);
+    if url.scheme() != "https" && !allow_insecure {
+        return Err(anyhow::anyhow!(
+            "endpoint scheme '{}' is not permitted (set COLLECTOR_ALLOW_INSECURE to allow)",
+            url.scheme()
+        ));
+    }
+
+    let host = url
+        .host_str()
+        .context("endpoint has no host")?
+        .to_string();
+
+    let allowlist = Allowlist::from_env();
+
+    // IP literal: validate directly.
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        if allowlist.allows_ip(ip) {
+            return Ok(());
+        }
+        if is_blocked_ip(ip) {
+            return Err(anyhow::anyhow!("endpoint IP {ip} is in a blocked range"));
+        }
+        return Ok(());
+    }
+
+    // When a suffix allowlist is configured, the DNS name must match it; either
+    // way the name is still resolved and checked against the blocked ranges so
+    // an allowlisted name can't be pointed at an internal address.
+    if allowlist.has_suffixes() && !allowlist.allows_host(&host) {
+        return Err(anyhow::anyhow!(
+            "endpoint host '{host}' is not in the configured allowlist"
+        ));
+    }
+
+    // Resolve off the runtime worker and reject if any address lands in a
+    // blocked range.
+    let port = url.port_or_known_default().unwrap_or(443);
+    let resolve_host = host.clone();
+    let addrs = tokio::task::spawn_blocking(move || {
+        (resolve_host.as_str(), port)
+            .to_socket_addrs()
+            .map(|addrs| addrs.collect::<Vec<_>>())
+    })
+    .await
+    .context("DNS resolution task panicked")?
+    .with_context(|| format!("failed to resolve endpoint host '{host}'"))?;
+
+    for addr in addrs {
+        let ip = addr.ip();
+        if !allowlist.allows_ip(ip) && is_blocked_ip(ip) {
+            return Err(anyhow::anyhow!(
+                "endpoint host '{host}' resolves to blocked address {ip}"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Fetches the PEM RSA private key from Secrets Manager and parses it into an
+/// [`HttpSigner`], using the secret name as the signature `keyId`.
+async fn load_httpsig_signer(
+    client: &SecretsManagerClient,
+    key_name: &str,
+) -> Result<HttpSigner> {
+    let response = client
+        .get_secret_value()
+        .secret_id(key_name)
+        .send()
+        .await
+        .with_context(|| format!("failed to fetch httpsig secret '{key_name}'"))?;
+
+    let pem = response
+        .secret_string()
+        .with_context(|| format!("httpsig secret '{key_name}' has no string value"))?;
+
+    HttpSigner::from_pem(key_name, pem)
+}
+
 #[cfg(test)]
 pub(crate) mod test_utils {
     use super::*;
@@ -345,6 +1202,10 @@ mod tests {
             endpoint: "https://collector.example.com".to_string(),
             auth: None,
             exclude: None,
+            include: None,
+            priority: None,
+            retry: RetryPolicy::default(),
+            signer: None,
         };
 
         // Test with simple path
@@ -359,6 +1220,10 @@ mod tests {
             endpoint: "https://collector.example.com/base".to_string(),
             auth: None,
             exclude: None,
+            include: None,
+            priority: None,
+            retry: RetryPolicy::default(),
+            signer: None,
         };
         let result = collector_with_path
             .construct_signal_endpoint("https://original.com/v1/traces")
@@ -371,6 +1236,10 @@ mod tests {
             endpoint: "https://collector.example.com/".to_string(),
             auth: None,
             exclude: None,
+            include: None,
+            priority: None,
+            retry: RetryPolicy::default(),
+            signer: None,
         };
         let result = collector_with_slash
             .construct_signal_endpoint("https://original.com/v1/traces")
@@ -383,6 +1252,10 @@ mod tests {
             endpoint: "not a url".to_string(),
             auth: None,
             exclude: None,
+            include: None,
+            priority: None,
+            retry: RetryPolicy::default(),
+            signer: None,
         };
         assert!(collector
             .construct_signal_endpoint("https://original.com/v1/traces")
@@ -393,6 +1266,10 @@ mod tests {
             endpoint: "https://collector.example.com".to_string(),
             auth: None,
             exclude: None,
+            include: None,
+            priority: None,
+            retry: RetryPolicy::default(),
+            signer: None,
         };
         assert!(collector.construct_signal_endpoint("not a url").is_err());
     }
@@ -406,6 +1283,10 @@ mod tests {
             endpoint: "https://collector.example.com".to_string(),
             auth: None,
             exclude: None,
+            include: None,
+            priority: None,
+            retry: RetryPolicy::default(),
+            signer: None,
         }]);
 
         let cache = CollectorsCache::new(collectors);
@@ -454,6 +1335,298 @@ mod tests {
         assert!(!collector.should_exclude("/aws/spans")); // Should not exclude when regex is invalid
     }
 
+    #[test]
+    fn test_collector_matches_include_only() {
+        let collector: Collector = serde_json::from_value(json!({
+            "name": "test",
+            "endpoint": "https://collector.example.com",
+            "include": "/aws/lambda/.*"
+        }))
+        .unwrap();
+
+        assert!(collector.matches("/aws/lambda/function"));
+        assert!(!collector.matches("/aws/rds/instance"));
+    }
+
+    #[test]
+    fn test_collector_matches_include_exclude_overlap() {
+        // Include broadly but exclude a narrower subset; exclude wins on overlap.
+        let collector: Collector = serde_json::from_value(json!({
+            "name": "test",
+            "endpoint": "https://collector.example.com",
+            "include": "/aws/lambda/.*",
+            "exclude": "/aws/lambda/secret"
+        }))
+        .unwrap();
+
+        assert!(collector.matches("/aws/lambda/function"));
+        assert!(!collector.matches("/aws/lambda/secret"));
+        assert!(!collector.matches("/aws/rds/instance"));
+    }
+
+    #[test]
+    fn test_collector_matches_invalid_include_treated_as_absent() {
+        // An invalid include regex is warned-and-dropped, so the collector
+        // matches everything (subject only to exclude).
+        let collector: Collector = serde_json::from_value(json!({
+            "name": "test",
+            "endpoint": "https://collector.example.com",
+            "include": "[invalid regex"
+        }))
+        .unwrap();
+
+        assert!(collector.matches("/aws/lambda/function"));
+    }
+
+    #[test]
+    fn test_priority_tie_breaking() {
+        let high_a = Collector {
+            name: "high-a".to_string(),
+            endpoint: "https://a.example.com".to_string(),
+            auth: None,
+            exclude: None,
+            include: None,
+            priority: Some(10),
+            retry: RetryPolicy::default(),
+            signer: None,
+        };
+        let high_b = Collector {
+            priority: Some(10),
+            endpoint: "https://b.example.com".to_string(),
+            name: "high-b".to_string(),
+            ..high_a.clone()
+        };
+        let low = Collector {
+            priority: Some(1),
+            endpoint: "https://c.example.com".to_string(),
+            name: "low".to_string(),
+            ..high_a.clone()
+        };
+        // An un-prioritized collector is treated as below any explicit priority
+        // and is dropped once any match sets one.
+        let unprioritized = Collector {
+            priority: None,
+            endpoint: "https://d.example.com".to_string(),
+            name: "none".to_string(),
+            ..high_a.clone()
+        };
+
+        // Drive the real selection logic directly (rather than through the
+        // process-wide `COLLECTORS` cache, which is a set-once `OnceLock`
+        // shared with other tests) so a regression in the
+        // breaker-vs-priority ordering would be caught here.
+        let matching = vec![&high_a, &low, &high_b, &unprioritized];
+        let mut selected: Vec<String> =
+            select_endpoints(&matching, "https://original.com/v1/traces")
+                .into_iter()
+                .map(|c| c.name)
+                .collect();
+        selected.sort();
+
+        // Both priority-10 collectors are kept; the priority-1 and the
+        // un-prioritized collectors are dropped.
+        assert_eq!(selected, vec!["high-a".to_string(), "high-b".to_string()]);
+    }
+
+    #[test]
+    fn test_priority_failover_when_top_tier_is_circuit_broken() {
+        let high = Collector {
+            name: "high".to_string(),
+            endpoint: "https://failover-high.example.com".to_string(),
+            auth: None,
+            exclude: None,
+            include: None,
+            priority: Some(10),
+            retry: RetryPolicy::default(),
+            signer: None,
+        };
+        let low = Collector {
+            priority: Some(1),
+            endpoint: "https://failover-low.example.com".to_string(),
+            name: "low".to_string(),
+            ..high.clone()
+        };
+
+        // Trip the top tier's breaker open so should_try refuses it.
+        breakers().record_failure(&high.endpoint);
+        breakers().record_failure(&high.endpoint);
+        breakers().record_failure(&high.endpoint);
+        breakers().record_failure(&high.endpoint);
+        breakers().record_failure(&high.endpoint);
+
+        let matching = vec![&high, &low];
+        let selected: Vec<String> = select_endpoints(&matching, "https://original.com/v1/traces")
+            .into_iter()
+            .map(|c| c.name)
+            .collect();
+
+        // The fully circuit-broken top tier fails over to the healthy
+        // lower-priority tier instead of dropping the signal.
+        assert_eq!(selected, vec!["low".to_string()]);
+    }
+
+    #[test]
+    fn test_breaker_trips_after_threshold() {
+        let breakers = Breakers {
+            inner: DashMap::new(),
+            threshold: 3,
+            cooldown: Duration::from_secs(30),
+        };
+        let endpoint = "https://collector.example.com/v1/traces";
+
+        // Closed breaker always allows requests.
+        assert!(breakers.should_try(endpoint));
+
+        // Below the threshold the breaker stays closed.
+        breakers.record_failure(endpoint);
+        breakers.record_failure(endpoint);
+        assert!(breakers.should_try(endpoint));
+
+        // The third consecutive failure trips it open.
+        breakers.record_failure(endpoint);
+        assert!(!breakers.should_try(endpoint));
+    }
+
+    #[test]
+    fn test_breaker_success_resets() {
+        let breakers = Breakers {
+            inner: DashMap::new(),
+            threshold: 2,
+            cooldown: Duration::from_secs(30),
+        };
+        let endpoint = "https://collector.example.com/v1/traces";
+
+        breakers.record_failure(endpoint);
+        breakers.record_success(endpoint);
+        // The success cleared the count, so one more failure is not enough.
+        breakers.record_failure(endpoint);
+        assert!(breakers.should_try(endpoint));
+    }
+
+    #[test]
+    fn test_breaker_half_open_probe() {
+        let breakers = Breakers {
+            inner: DashMap::new(),
+            threshold: 1,
+            cooldown: Duration::from_millis(0),
+        };
+        let endpoint = "https://collector.example.com/v1/traces";
+
+        // Trip the breaker open with a zero cooldown.
+        breakers.record_failure(endpoint);
+
+        // Cooldown already elapsed: the next check transitions to half-open and
+        // allows a single probe.
+        assert!(breakers.should_try(endpoint));
+
+        // While that probe is outstanding, no further requests are admitted.
+        assert!(!breakers.should_try(endpoint));
+
+        // A failure while half-open re-opens immediately.
+        breakers.record_failure(endpoint);
+        let authority = Breakers::authority(endpoint).unwrap();
+        assert!(matches!(
+            breakers.inner.get(&authority).unwrap().state,
+            BreakerState::Open { .. }
+        ));
+    }
+
+    #[test]
+    fn test_is_blocked_ip() {
+        // Cloud metadata service and private/loopback ranges are blocked.
+        assert!(is_blocked_ip("169.254.169.254".parse().unwrap()));
+        assert!(is_blocked_ip("127.0.0.1".parse().unwrap()));
+        assert!(is_blocked_ip("10.0.0.5".parse().unwrap()));
+        assert!(is_blocked_ip("192.168.1.1".parse().unwrap()));
+        assert!(is_blocked_ip("::1".parse().unwrap()));
+
+        // IPv4-mapped IPv6 literals are unmapped and classified as the
+        // embedded IPv4 address.
+        assert!(is_blocked_ip("::ffff:169.254.169.254".parse().unwrap()));
+
+        // Public addresses are allowed.
+        assert!(!is_blocked_ip("8.8.8.8".parse().unwrap()));
+        assert!(!is_blocked_ip("2606:4700:4700::1111".parse().unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_validate_endpoint_rejects_private_and_insecure() {
+        // IP literal in a blocked range is rejected.
+        assert!(validate_endpoint("https://169.254.169.254/v1/traces")
+            .await
+            .is_err());
+
+        // Non-https scheme is rejected by default.
+        assert!(validate_endpoint("http://collector.example.com/v1/traces")
+            .await
+            .is_err());
+
+        // Public IP literal over https is accepted.
+        assert!(validate_endpoint("https://8.8.8.8/v1/traces").await.is_ok());
+    }
+
+    #[test]
+    fn test_allowlist_suffix_is_label_anchored() {
+        let allowlist = Allowlist {
+            cidrs: Vec::new(),
+            suffixes: vec!["example.com".to_string()],
+        };
+
+        // Exact host and proper subdomains match.
+        assert!(allowlist.allows_host("example.com"));
+        assert!(allowlist.allows_host("api.example.com"));
+
+        // Look-alike hosts sharing the suffix as a substring do not.
+        assert!(!allowlist.allows_host("evilexample.com"));
+        assert!(!allowlist.allows_host("attacker-example.com"));
+    }
+
+    #[test]
+    fn test_signal_type_from_path() {
+        assert_eq!(signal_type("/v1/traces"), "traces");
+        assert_eq!(signal_type("/v1/logs"), "logs");
+        assert_eq!(signal_type("/v1/metrics"), "metrics");
+        assert_eq!(signal_type("/v1/unknown"), "unknown");
+    }
+
+    #[test]
+    fn test_retry_policy_defaults() {
+        // Absent retry block falls back to defaults.
+        let collector: Collector = serde_json::from_value(json!({
+            "name": "test",
+            "endpoint": "https://collector.example.com"
+        }))
+        .unwrap();
+        assert_eq!(collector.retry.max_retries, 3);
+        assert_eq!(collector.retry.base_delay_ms, 100);
+        assert_eq!(collector.retry.max_delay_ms, 5_000);
+
+        // Partial retry block keeps defaults for the omitted fields.
+        let collector: Collector = serde_json::from_value(json!({
+            "name": "test",
+            "endpoint": "https://collector.example.com",
+            "retry": { "max_retries": 7 }
+        }))
+        .unwrap();
+        assert_eq!(collector.retry.max_retries, 7);
+        assert_eq!(collector.retry.base_delay_ms, 100);
+    }
+
+    #[test]
+    fn test_backoff_delay_is_capped() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            base_delay_ms: 100,
+            max_delay_ms: 1_000,
+        };
+
+        // The full-jitter delay never exceeds the cap for any attempt.
+        for attempt in 0..10 {
+            let delay = policy.backoff_delay(attempt);
+            assert!(delay <= Duration::from_millis(1_000));
+        }
+    }
+
     #[test]
     fn test_collector_deserialization_with_exclude() {
         let valid_json = json!({